@@ -1,10 +1,15 @@
-use anyhow::{Context, Result};
-use clap::Parser;
-use csv::{Reader, Writer};
-use geoconvert::Mgrs;
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use csv::{ByteRecord, Reader, ReaderBuilder, StringRecord, Writer, WriterBuilder};
+use geoconvert::{LatLon, Mgrs};
 use regex::Regex;
+use serde_json::{json, Map, Value};
+use std::fmt::Write as _;
 use std::fs::File;
-use std::io::{self, BufReader, BufWriter};
+use std::io::{self, BufReader, BufWriter, Write as _};
+
+/// Number of records sampled from the start of the file to autodetect the MGRS column.
+const DETECTION_SAMPLE_SIZE: usize = 100;
 
 #[derive(Parser)]
 #[command(name = "mgrs2latlong")]
@@ -16,6 +21,133 @@ struct Cli {
     
     #[arg(short, long, help = "Output CSV file path (defaults to stdout)")]
     output: Option<String>,
+
+    #[arg(long, default_value = ",", help = "Field delimiter for both input and output")]
+    delimiter: char,
+
+    #[arg(long, default_value = "\"", help = "Quote character for both input and output")]
+    quote: char,
+
+    #[arg(long, help = "Allow records with a varying number of fields")]
+    flexible: bool,
+
+    #[arg(long, help = "Shortcut for --delimiter <tab> (input and output)")]
+    tsv: bool,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Csv, help = "Output format")]
+    output_format: OutputFormat,
+
+    #[arg(
+        long,
+        help = "Exit successfully even if some rows failed to convert; in GeoJSON output, also skip those rows instead of emitting null geometry"
+    )]
+    skip_invalid: bool,
+
+    #[arg(
+        short = 'c',
+        long = "column",
+        help = "MGRS column name or 0-based index to convert (repeatable); overrides autodetection"
+    )]
+    columns: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Write rows that failed MGRS conversion here, with an added 'error' column"
+    )]
+    rejects: Option<String>,
+
+    #[arg(long, help = "Convert latitude/longitude columns to an MGRS column instead")]
+    reverse: bool,
+
+    #[arg(
+        long,
+        value_parser = clap::value_parser!(u8).range(1..=5),
+        default_value_t = 5,
+        help = "MGRS easting/northing digit precision (1-5), used with --reverse"
+    )]
+    precision: u8,
+
+    #[arg(
+        long,
+        help = "Keep only rows whose converted coordinate falls within min_lat,min_lon,max_lat,max_lon"
+    )]
+    bbox: Option<String>,
+
+    #[arg(long, help = "Keep only rows whose MGRS grid zone designator matches (e.g. 33U)")]
+    gzs: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Csv,
+    Geojson,
+}
+
+impl Cli {
+    /// Resolves the effective field delimiter, honoring `--tsv` over `--delimiter`.
+    fn delimiter_byte(&self) -> Result<u8> {
+        if self.tsv {
+            Ok(b'\t')
+        } else {
+            char_to_byte(self.delimiter, "--delimiter")
+        }
+    }
+
+    fn quote_byte(&self) -> Result<u8> {
+        char_to_byte(self.quote, "--quote")
+    }
+
+    /// Parses `--bbox` into a `BoundingBox`, if given.
+    fn resolved_bbox(&self) -> Result<Option<BoundingBox>> {
+        self.bbox.as_deref().map(parse_bbox).transpose()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct BoundingBox {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl BoundingBox {
+    fn contains(&self, lat: f64, lon: f64) -> bool {
+        lat >= self.min_lat && lat <= self.max_lat && lon >= self.min_lon && lon <= self.max_lon
+    }
+}
+
+fn parse_bbox(spec: &str) -> Result<BoundingBox> {
+    let parts: Vec<&str> = spec.split(',').map(str::trim).collect();
+    if parts.len() != 4 {
+        bail!("--bbox expects 4 comma-separated values: min_lat,min_lon,max_lat,max_lon");
+    }
+
+    Ok(BoundingBox {
+        min_lat: parts[0].parse().with_context(|| "Invalid --bbox min_lat")?,
+        min_lon: parts[1].parse().with_context(|| "Invalid --bbox min_lon")?,
+        max_lat: parts[2].parse().with_context(|| "Invalid --bbox max_lat")?,
+        max_lon: parts[3].parse().with_context(|| "Invalid --bbox max_lon")?,
+    })
+}
+
+/// Extracts the MGRS grid zone designator (e.g. "33U") from the leading zone number and band
+/// letter of an MGRS string, for `--gzs` filtering.
+fn extract_gzs(mgrs_value: &str) -> Option<String> {
+    let normalized = mgrs_value.replace(' ', "").to_uppercase();
+    let letter_pos = normalized.find(|c: char| c.is_ascii_alphabetic())?;
+    if letter_pos == 0 || letter_pos > 2 {
+        return None;
+    }
+    Some(normalized[..letter_pos + 1].to_string())
+}
+
+fn char_to_byte(c: char, flag: &str) -> Result<u8> {
+    if c.is_ascii() {
+        Ok(c as u8)
+    } else {
+        bail!("{} must be a single ASCII character, got '{}'", flag, c)
+    }
 }
 
 fn is_likely_mgrs(value: &str) -> bool {
@@ -32,22 +164,27 @@ fn convert_mgrs_to_latlon(mgrs_str: &str) -> Result<(f64, f64)> {
     Ok((latlon.latitude(), latlon.longitude()))
 }
 
-fn detect_mgrs_column(records: &[csv::StringRecord]) -> Option<usize> {
-    if records.is_empty() {
-        return None;
-    }
-    
-    let num_columns = records[0].len();
+fn convert_latlon_to_mgrs(lat: f64, lon: f64, precision: u8) -> Result<String> {
+    let latlon = LatLon::create(lat, lon)
+        .with_context(|| format!("Failed to create coordinate from ({}, {})", lat, lon))?;
+    let mgrs = latlon.to_mgrs(i32::from(precision));
+    Ok(mgrs.to_string())
+}
+
+fn detect_mgrs_column(sample: &[ByteRecord]) -> Option<usize> {
+    let num_columns = sample.first()?.len();
     let mut column_scores = vec![0; num_columns];
-    
-    for record in records.iter().take(100) {
+
+    for record in sample {
         for (col_idx, field) in record.iter().enumerate() {
-            if is_likely_mgrs(field.trim()) {
-                column_scores[col_idx] += 1;
+            if let Ok(field) = std::str::from_utf8(field) {
+                if is_likely_mgrs(field.trim()) {
+                    column_scores[col_idx] += 1;
+                }
             }
         }
     }
-    
+
     column_scores
         .iter()
         .enumerate()
@@ -56,73 +193,622 @@ fn detect_mgrs_column(records: &[csv::StringRecord]) -> Option<usize> {
         .map(|(idx, _)| idx)
 }
 
-fn process_csv(input_path: &str, output_path: Option<&str>) -> Result<()> {
+/// Looks for header names containing "lat" and "lon"/"lng" (case-insensitive substring match,
+/// e.g. `lat`, `Latitude`, `lon`, `lng`); the first matching header of each kind is used. Takes
+/// priority over the range-based scoring below, which can't tell a real coordinate column from
+/// an incidental numeric one (an `id` or `year` column).
+fn header_latlon_columns(headers: &StringRecord) -> Option<(usize, usize)> {
+    let lat_col = headers
+        .iter()
+        .position(|h| h.to_lowercase().contains("lat"))?;
+    let lon_col = headers
+        .iter()
+        .position(|h| {
+            let h = h.to_lowercase();
+            h.contains("lon") || h.contains("lng")
+        })
+        .filter(|&idx| idx != lat_col)?;
+
+    Some((lat_col, lon_col))
+}
+
+/// Scores each column by how many sampled values parse as a fractional latitude (|v| <= 90) or
+/// a fractional longitude (|v| <= 180), then picks the best-scoring distinct pair. Only values
+/// with a decimal point count, so that integer columns like `id` or `year` - which trivially fall
+/// within both ranges - don't outscore the real coordinate columns.
+fn detect_latlon_columns(sample: &[ByteRecord]) -> Option<(usize, usize)> {
+    let num_columns = sample.first()?.len();
+    let mut lat_scores = vec![0u32; num_columns];
+    let mut lon_scores = vec![0u32; num_columns];
+
+    for record in sample {
+        for (col_idx, field) in record.iter().enumerate() {
+            let Ok(field) = std::str::from_utf8(field) else { continue };
+            let field = field.trim();
+            if !field.contains('.') {
+                continue;
+            }
+            let Ok(value) = field.parse::<f64>() else { continue };
+
+            if (-90.0..=90.0).contains(&value) {
+                lat_scores[col_idx] += 1;
+            }
+            if (-180.0..=180.0).contains(&value) {
+                lon_scores[col_idx] += 1;
+            }
+        }
+    }
+
+    // Every latitude is also a valid longitude value (|v| <= 90 implies |v| <= 180), so columns
+    // routinely tie on both scores. Break ties toward the earlier column: by convention lat/lon
+    // pairs are listed latitude-first, and `max_by_key` alone resolves ties toward the *last* index.
+    let lat_col = lat_scores
+        .iter()
+        .enumerate()
+        .max_by_key(|&(idx, score)| (*score, std::cmp::Reverse(idx)))
+        .filter(|&(_, score)| *score > 0)
+        .map(|(idx, _)| idx)?;
+
+    let lon_col = lon_scores
+        .iter()
+        .enumerate()
+        .filter(|&(idx, _)| idx != lat_col)
+        .max_by_key(|&(idx, score)| (*score, std::cmp::Reverse(idx)))
+        .filter(|&(_, score)| *score > 0)
+        .map(|(idx, _)| idx)?;
+
+    Some((lat_col, lon_col))
+}
+
+fn build_reader(cli: &Cli, input_path: &str) -> Result<Reader<BufReader<File>>> {
     let file = File::open(input_path)
         .with_context(|| format!("Failed to open input file: {}", input_path))?;
-    let mut reader = Reader::from_reader(BufReader::new(file));
-    
-    let mut records = Vec::new();
+    Ok(ReaderBuilder::new()
+        .delimiter(cli.delimiter_byte()?)
+        .quote(cli.quote_byte()?)
+        .flexible(cli.flexible)
+        .from_reader(BufReader::new(file)))
+}
+
+/// Samples the first `DETECTION_SAMPLE_SIZE` records of `input_path` to find the MGRS column,
+/// without buffering the rest of the file.
+fn sample_mgrs_column(cli: &Cli, input_path: &str) -> Result<usize> {
+    let mut reader = build_reader(cli, input_path)?;
+
+    let mut sample = Vec::with_capacity(DETECTION_SAMPLE_SIZE);
+    let mut record = ByteRecord::new();
+    while sample.len() < DETECTION_SAMPLE_SIZE && reader.read_byte_record(&mut record)? {
+        sample.push(record.clone());
+    }
+
+    detect_mgrs_column(&sample).with_context(|| "No MGRS-like column detected in the CSV file")
+}
+
+/// Samples the first `DETECTION_SAMPLE_SIZE` records of `input_path` to find the latitude and
+/// longitude columns for `--reverse` mode. Header names take priority over value sniffing; see
+/// `header_latlon_columns`.
+fn sample_latlon_columns(cli: &Cli, input_path: &str) -> Result<(usize, usize)> {
+    let mut reader = build_reader(cli, input_path)?;
     let headers = reader.headers()?.clone();
-    
-    for result in reader.records() {
-        let record = result.with_context(|| "Failed to read CSV record")?;
-        records.push(record);
+
+    if let Some(cols) = header_latlon_columns(&headers) {
+        return Ok(cols);
     }
-    
-    let mgrs_column = detect_mgrs_column(&records)
-        .with_context(|| "No MGRS-like column detected in the CSV file")?;
-    
-    let output: Box<dyn io::Write> = match output_path {
+
+    let mut sample = Vec::with_capacity(DETECTION_SAMPLE_SIZE);
+    let mut record = ByteRecord::new();
+    while sample.len() < DETECTION_SAMPLE_SIZE && reader.read_byte_record(&mut record)? {
+        sample.push(record.clone());
+    }
+
+    detect_latlon_columns(&sample)
+        .with_context(|| "No latitude/longitude columns detected in the CSV file")
+}
+
+/// Resolves which columns to convert: explicit `--column` values if given (by header name or
+/// 0-based index), otherwise the single autodetected MGRS column.
+fn resolve_mgrs_columns(
+    cli: &Cli,
+    headers: &StringRecord,
+    input_path: &str,
+) -> Result<Vec<usize>> {
+    if cli.columns.is_empty() {
+        return Ok(vec![sample_mgrs_column(cli, input_path)?]);
+    }
+
+    cli.columns
+        .iter()
+        .map(|spec| resolve_column(spec, headers))
+        .collect()
+}
+
+fn resolve_column(spec: &str, headers: &StringRecord) -> Result<usize> {
+    if let Some(idx) = headers.iter().position(|h| h == spec) {
+        return Ok(idx);
+    }
+
+    spec.parse::<usize>()
+        .ok()
+        .filter(|&idx| idx < headers.len())
+        .with_context(|| {
+            format!(
+                "Column '{}' is neither a header in the CSV nor a valid column index",
+                spec
+            )
+        })
+}
+
+/// Returns the display label used to disambiguate appended lat/lon headers for `col`.
+fn column_label(headers: &StringRecord, col: usize) -> String {
+    headers
+        .get(col)
+        .filter(|h| !h.is_empty())
+        .map(|h| h.to_string())
+        .unwrap_or_else(|| col.to_string())
+}
+
+/// Attempts to convert `mgrs_value`. `Ok(None)` means the value doesn't look like an MGRS
+/// coordinate and was intentionally left blank, not a conversion failure.
+fn try_convert(mgrs_value: &str) -> std::result::Result<Option<(f64, f64)>, String> {
+    if mgrs_value.is_empty() || !is_likely_mgrs(mgrs_value) {
+        return Ok(None);
+    }
+    convert_mgrs_to_latlon(mgrs_value)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+/// Attempts to convert a latitude/longitude pair to MGRS. `Ok(None)` means the fields don't
+/// look like coordinates and were intentionally left blank, not a conversion failure.
+fn try_convert_reverse(
+    lat_str: &str,
+    lon_str: &str,
+    precision: u8,
+) -> std::result::Result<Option<String>, String> {
+    let (Ok(lat), Ok(lon)) = (lat_str.trim().parse::<f64>(), lon_str.trim().parse::<f64>()) else {
+        return Ok(None);
+    };
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return Ok(None);
+    }
+
+    convert_latlon_to_mgrs(lat, lon, precision)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+fn open_rejects_writer(
+    cli: &Cli,
+    headers: &StringRecord,
+) -> Result<Option<Writer<BufWriter<File>>>> {
+    let Some(path) = cli.rejects.as_deref() else {
+        return Ok(None);
+    };
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create rejects file: {}", path))?;
+    let mut writer = WriterBuilder::new()
+        .delimiter(cli.delimiter_byte()?)
+        .quote(cli.quote_byte()?)
+        .from_writer(BufWriter::new(file));
+
+    let mut reject_headers = headers.iter().map(str::to_string).collect::<Vec<_>>();
+    reject_headers.push("error".to_string());
+    writer
+        .write_record(&reject_headers)
+        .with_context(|| "Failed to write rejects file headers")?;
+
+    Ok(Some(writer))
+}
+
+/// Tracks how many rows were processed and how many failed MGRS conversion, so `main` can
+/// decide the process exit status.
+struct ConversionSummary {
+    total: usize,
+    failed: usize,
+}
+
+fn report_and_check(summary: &ConversionSummary, skip_invalid: bool) -> Result<()> {
+    if summary.failed > 0 {
+        eprintln!(
+            "{} of {} records failed MGRS conversion.",
+            summary.failed, summary.total
+        );
+        if !skip_invalid {
+            bail!("{} record(s) failed to convert; pass --skip-invalid to ignore", summary.failed);
+        }
+    }
+    Ok(())
+}
+
+fn process_csv(cli: &Cli, input_path: &str, output_path: Option<&str>) -> Result<()> {
+    let summary = if cli.reverse {
+        process_latlon_to_mgrs(cli, input_path, output_path)?
+    } else {
+        match cli.output_format {
+            OutputFormat::Csv => process_csv_to_csv(cli, input_path, output_path)?,
+            OutputFormat::Geojson => process_csv_to_geojson(cli, input_path, output_path)?,
+        }
+    };
+    report_and_check(&summary, cli.skip_invalid)
+}
+
+fn open_output(output_path: Option<&str>) -> Result<Box<dyn io::Write>> {
+    Ok(match output_path {
         Some(path) => {
             let file = File::create(path)
                 .with_context(|| format!("Failed to create output file: {}", path))?;
             Box::new(BufWriter::new(file))
         }
         None => Box::new(io::stdout()),
-    };
-    
-    let mut writer = Writer::from_writer(output);
-    
-    let mut new_headers = headers.iter().collect::<Vec<_>>();
-    new_headers.push("Latitude");
-    new_headers.push("Longitude");
+    })
+}
+
+fn process_csv_to_csv(
+    cli: &Cli,
+    input_path: &str,
+    output_path: Option<&str>,
+) -> Result<ConversionSummary> {
+    let mut reader = build_reader(cli, input_path)?;
+    let headers = reader.headers()?.clone();
+    let columns = resolve_mgrs_columns(cli, &headers, input_path)?;
+    let mut rejects = open_rejects_writer(cli, &headers)?;
+    let bbox = cli.resolved_bbox()?;
+
+    let output = open_output(output_path)?;
+
+    let mut writer = WriterBuilder::new()
+        .delimiter(cli.delimiter_byte()?)
+        .quote(cli.quote_byte()?)
+        .flexible(cli.flexible)
+        .from_writer(output);
+
+    let mut new_headers = headers.iter().map(str::to_string).collect::<Vec<_>>();
+    if columns.len() == 1 {
+        new_headers.push("Latitude".to_string());
+        new_headers.push("Longitude".to_string());
+    } else {
+        for &col in &columns {
+            let label = column_label(&headers, col);
+            new_headers.push(format!("Latitude_{}", label));
+            new_headers.push(format!("Longitude_{}", label));
+        }
+    }
     writer.write_record(&new_headers)
         .with_context(|| "Failed to write headers")?;
-    
-    for record in &records {
-        let mut new_record = record.iter().collect::<Vec<_>>();
-        
-        let mgrs_value = record.get(mgrs_column).unwrap_or("").trim();
-        
-        let (lat_str, lon_str) = if !mgrs_value.is_empty() && is_likely_mgrs(mgrs_value) {
-            match convert_mgrs_to_latlon(mgrs_value) {
-                Ok((lat, lon)) => (lat.to_string(), lon.to_string()),
-                Err(_) => (String::new(), String::new())
+
+    // Scratch buffers reused across rows so the input record, output record and appended
+    // lat/lon fields are not reallocated for each row.
+    let mut record = ByteRecord::new();
+    let mut out_record = ByteRecord::new();
+    let mut lat_bufs = vec![String::new(); columns.len()];
+    let mut lon_bufs = vec![String::new(); columns.len()];
+    let mut count = 0usize;
+    let mut failed = 0usize;
+    let mut row_errors: Vec<String> = Vec::new();
+
+    // The first resolved column is the one `--bbox`/`--gzs` filter on.
+    let mut primary_mgrs_value = String::new();
+
+    while reader.read_byte_record(&mut record)? {
+        count += 1;
+        row_errors.clear();
+        let mut primary_latlon: Option<(f64, f64)> = None;
+
+        for (i, &col) in columns.iter().enumerate() {
+            let mgrs_value = record
+                .get(col)
+                .and_then(|field| std::str::from_utf8(field).ok())
+                .unwrap_or("")
+                .trim();
+
+            if i == 0 {
+                primary_mgrs_value.clear();
+                primary_mgrs_value.push_str(mgrs_value);
             }
-        } else {
-            (String::new(), String::new())
+
+            lat_bufs[i].clear();
+            lon_bufs[i].clear();
+            match try_convert(mgrs_value) {
+                Ok(Some((lat, lon))) => {
+                    let _ = write!(lat_bufs[i], "{}", lat);
+                    let _ = write!(lon_bufs[i], "{}", lon);
+                    if i == 0 {
+                        primary_latlon = Some((lat, lon));
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => row_errors.push(format!("column '{}': {}", column_label(&headers, col), e)),
+            }
+        }
+
+        if !row_errors.is_empty() {
+            failed += 1;
+            let position = record.position().map_or(count as u64, |p| p.line());
+            let message = row_errors.join("; ");
+            eprintln!("Row {}: {}", position, message);
+
+            if let Some(rejects) = rejects.as_mut() {
+                let mut reject_fields: Vec<&[u8]> = record.iter().collect();
+                reject_fields.push(message.as_bytes());
+                rejects
+                    .write_record(&reject_fields)
+                    .with_context(|| "Failed to write rejected record")?;
+            }
+        }
+
+        if let Some(bbox) = &bbox {
+            if !primary_latlon.is_some_and(|(lat, lon)| bbox.contains(lat, lon)) {
+                continue;
+            }
+        }
+        if let Some(gzs) = cli.gzs.as_deref() {
+            if !extract_gzs(&primary_mgrs_value).is_some_and(|zone| zone.eq_ignore_ascii_case(gzs)) {
+                continue;
+            }
+        }
+
+        out_record.clear();
+        for field in record.iter() {
+            out_record.push_field(field);
+        }
+        for i in 0..columns.len() {
+            out_record.push_field(lat_bufs[i].as_bytes());
+            out_record.push_field(lon_bufs[i].as_bytes());
+        }
+
+        writer
+            .write_byte_record(&out_record)
+            .with_context(|| "Failed to write record")?;
+    }
+
+    writer.flush()
+        .with_context(|| "Failed to flush output")?;
+    if let Some(rejects) = rejects.as_mut() {
+        rejects.flush().with_context(|| "Failed to flush rejects file")?;
+    }
+
+    println!("Processed {} records. MGRS column(s) at index {:?}.",
+             count, columns);
+
+    Ok(ConversionSummary { total: count, failed })
+}
+
+/// Writes a GeoJSON `FeatureCollection`, one `Feature` per row, streaming features to `output`
+/// as they're converted rather than buffering the whole collection in memory.
+fn process_csv_to_geojson(
+    cli: &Cli,
+    input_path: &str,
+    output_path: Option<&str>,
+) -> Result<ConversionSummary> {
+    let mut reader = build_reader(cli, input_path)?;
+    let headers = reader.headers()?.clone();
+    let columns = resolve_mgrs_columns(cli, &headers, input_path)?;
+    let mut rejects = open_rejects_writer(cli, &headers)?;
+    let bbox = cli.resolved_bbox()?;
+
+    let mut output = open_output(output_path)?;
+    write!(output, r#"{{"type":"FeatureCollection","features":["#)?;
+
+    let mut record = ByteRecord::new();
+    let mut count = 0usize;
+    let mut emitted = 0usize;
+    let mut failed = 0usize;
+
+    while reader.read_byte_record(&mut record)? {
+        count += 1;
+
+        // The first resolved column provides the feature's geometry; with --column given
+        // multiple times, every resolved column (the first included) is also carried as
+        // Latitude_<col>/Longitude_<col> properties.
+        let mut row_errors: Vec<String> = Vec::new();
+        let latlons: Vec<Option<(f64, f64)>> = columns
+            .iter()
+            .map(|&col| {
+                let mgrs_value = record
+                    .get(col)
+                    .and_then(|field| std::str::from_utf8(field).ok())
+                    .unwrap_or("")
+                    .trim();
+                match try_convert(mgrs_value) {
+                    Ok(latlon) => latlon,
+                    Err(e) => {
+                        row_errors.push(format!("column '{}': {}", column_label(&headers, col), e));
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        if !row_errors.is_empty() {
+            failed += 1;
+            let position = record.position().map_or(count as u64, |p| p.line());
+            let message = row_errors.join("; ");
+            eprintln!("Row {}: {}", position, message);
+
+            if let Some(rejects) = rejects.as_mut() {
+                let mut reject_fields: Vec<&[u8]> = record.iter().collect();
+                reject_fields.push(message.as_bytes());
+                rejects
+                    .write_record(&reject_fields)
+                    .with_context(|| "Failed to write rejected record")?;
+            }
+        }
+
+        if !row_errors.is_empty() && cli.skip_invalid {
+            continue;
+        }
+        if let Some(bbox) = &bbox {
+            if !latlons[0].is_some_and(|(lat, lon)| bbox.contains(lat, lon)) {
+                continue;
+            }
+        }
+        if let Some(gzs) = cli.gzs.as_deref() {
+            let primary_mgrs_value = record
+                .get(columns[0])
+                .and_then(|field| std::str::from_utf8(field).ok())
+                .unwrap_or("")
+                .trim();
+            if !extract_gzs(primary_mgrs_value).is_some_and(|zone| zone.eq_ignore_ascii_case(gzs)) {
+                continue;
+            }
+        }
+
+        let mut properties = Map::with_capacity(headers.len());
+        for (header, field) in headers.iter().zip(record.iter()) {
+            let value = std::str::from_utf8(field).unwrap_or("");
+            properties.insert(header.to_string(), Value::String(value.to_string()));
+        }
+        if columns.len() > 1 {
+            for (&col, latlon) in columns.iter().zip(latlons.iter()) {
+                let label = column_label(&headers, col);
+                let (lat, lon) = latlon.unwrap_or_default();
+                properties.insert(format!("Latitude_{}", label), json!(lat));
+                properties.insert(format!("Longitude_{}", label), json!(lon));
+            }
+        }
+
+        let geometry = match latlons[0] {
+            Some((lat, lon)) => json!({
+                "type": "Point",
+                "coordinates": [lon, lat],
+            }),
+            None => Value::Null,
         };
-        
-        new_record.push(&lat_str);
-        new_record.push(&lon_str);
-        
-        writer.write_record(&new_record)
+
+        let feature = json!({
+            "type": "Feature",
+            "geometry": geometry,
+            "properties": properties,
+        });
+
+        if emitted > 0 {
+            write!(output, ",")?;
+        }
+        serde_json::to_writer(&mut output, &feature)
+            .with_context(|| "Failed to write GeoJSON feature")?;
+        emitted += 1;
+    }
+
+    write!(output, "]}}")?;
+    output.flush().with_context(|| "Failed to flush output")?;
+    if let Some(rejects) = rejects.as_mut() {
+        rejects.flush().with_context(|| "Failed to flush rejects file")?;
+    }
+
+    eprintln!("Processed {} records ({} features emitted). MGRS column(s) at index {:?}.",
+             count, emitted, columns);
+
+    Ok(ConversionSummary { total: count, failed })
+}
+
+/// Converts autodetected latitude/longitude columns to an appended `MGRS` column (`--reverse`).
+fn process_latlon_to_mgrs(
+    cli: &Cli,
+    input_path: &str,
+    output_path: Option<&str>,
+) -> Result<ConversionSummary> {
+    let mut reader = build_reader(cli, input_path)?;
+    let headers = reader.headers()?.clone();
+    let (lat_col, lon_col) = sample_latlon_columns(cli, input_path)?;
+    let mut rejects = open_rejects_writer(cli, &headers)?;
+    let bbox = cli.resolved_bbox()?;
+
+    let output = open_output(output_path)?;
+    let mut writer = WriterBuilder::new()
+        .delimiter(cli.delimiter_byte()?)
+        .quote(cli.quote_byte()?)
+        .flexible(cli.flexible)
+        .from_writer(output);
+
+    let mut new_headers = headers.iter().map(str::to_string).collect::<Vec<_>>();
+    new_headers.push("MGRS".to_string());
+    writer.write_record(&new_headers)
+        .with_context(|| "Failed to write headers")?;
+
+    // Scratch buffers reused across rows so the input record, output record and appended MGRS
+    // field are not reallocated for each row.
+    let mut record = ByteRecord::new();
+    let mut out_record = ByteRecord::new();
+    let mut mgrs_buf = String::new();
+    let mut count = 0usize;
+    let mut failed = 0usize;
+
+    while reader.read_byte_record(&mut record)? {
+        count += 1;
+
+        let lat_str = record
+            .get(lat_col)
+            .and_then(|field| std::str::from_utf8(field).ok())
+            .unwrap_or("");
+        let lon_str = record
+            .get(lon_col)
+            .and_then(|field| std::str::from_utf8(field).ok())
+            .unwrap_or("");
+
+        mgrs_buf.clear();
+        match try_convert_reverse(lat_str, lon_str, cli.precision) {
+            Ok(Some(mgrs)) => mgrs_buf.push_str(&mgrs),
+            Ok(None) => {}
+            Err(e) => {
+                failed += 1;
+                let position = record.position().map_or(count as u64, |p| p.line());
+                let message = format!("lat/lon columns: {}", e);
+                eprintln!("Row {}: {}", position, message);
+
+                if let Some(rejects) = rejects.as_mut() {
+                    let mut reject_fields: Vec<&[u8]> = record.iter().collect();
+                    reject_fields.push(message.as_bytes());
+                    rejects
+                        .write_record(&reject_fields)
+                        .with_context(|| "Failed to write rejected record")?;
+                }
+            }
+        }
+
+        if let Some(bbox) = &bbox {
+            let coords = lat_str
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .zip(lon_str.trim().parse::<f64>().ok());
+            if !coords.is_some_and(|(lat, lon)| bbox.contains(lat, lon)) {
+                continue;
+            }
+        }
+        if let Some(gzs) = cli.gzs.as_deref() {
+            if !extract_gzs(&mgrs_buf).is_some_and(|zone| zone.eq_ignore_ascii_case(gzs)) {
+                continue;
+            }
+        }
+
+        out_record.clear();
+        for field in record.iter() {
+            out_record.push_field(field);
+        }
+        out_record.push_field(mgrs_buf.as_bytes());
+
+        writer
+            .write_byte_record(&out_record)
             .with_context(|| "Failed to write record")?;
     }
-    
+
     writer.flush()
         .with_context(|| "Failed to flush output")?;
-    
-    println!("Processed {} records. MGRS column detected at index {}.", 
-             records.len(), mgrs_column);
-    
-    Ok(())
+    if let Some(rejects) = rejects.as_mut() {
+        rejects.flush().with_context(|| "Failed to flush rejects file")?;
+    }
+
+    println!("Processed {} records. Latitude/Longitude columns detected at index {} / {}.",
+             count, lat_col, lon_col);
+
+    Ok(ConversionSummary { total: count, failed })
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
-    
-    process_csv(&cli.input, cli.output.as_deref())?;
+
+    process_csv(&cli, &cli.input, cli.output.as_deref())?;
     
     Ok(())
 }
\ No newline at end of file